@@ -4,14 +4,28 @@ use coreum_wasm_sdk::assetft::{
 };
 use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
 use coreum_wasm_sdk::pagination::PageRequest;
-use cosmwasm_std::{coin, entry_point, to_json_binary, Binary, Deps, QueryRequest, StdResult};
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    coin, entry_point, to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, Order,
+    QueryRequest, StdResult, Storage, Uint128,
+};
+use cosmwasm_std::{BankQuery, DepsMut, Env, MessageInfo, Response, SupplyResponse};
 use cw2::set_contract_version;
 use cw_ownable::{assert_owner, initialize_owner};
+use cw_storage_plus::Bound;
+use cw_utils::Duration;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::DENOM;
+use crate::msg::{
+    ExecuteMsg, FreezeStatusResponse, InstantiateMsg, PoolResponse, QueryMsg,
+    TransactionHistoryResponse,
+};
+use crate::state::{
+    PoolConfig, PoolReserves, TxRecord, DENOM, FEE_RECIPIENTS, FREEZE_EXPIRATION, LP_SHARES,
+    MINTERS, POOL_CONFIG, POOL_RESERVES, TOTAL_LP_SHARES, TX_COUNT, TX_HISTORY,
+};
+
+const DEFAULT_HISTORY_LIMIT: u32 = 30;
+const MAX_HISTORY_LIMIT: u32 = 100;
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -57,39 +71,138 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> CoreumResult<ContractError> {
     match msg {
-        ExecuteMsg::Mint { amount, recipient } => mint(deps, info, amount, recipient),
-        ExecuteMsg::Burn { amount } => burn(deps, info, amount),
-        ExecuteMsg::Freeze { account, amount } => freeze(deps, info, account, amount),
-        ExecuteMsg::Unfreeze { account, amount } => unfreeze(deps, info, account, amount),
-        ExecuteMsg::SetFrozen { account, amount } => set_frozen(deps, info, account, amount),
-        ExecuteMsg::GloballyFreeze {} => globally_freeze(deps, info),
-        ExecuteMsg::GloballyUnfreeze {} => globally_unfreeze(deps, info),
+        ExecuteMsg::Mint { amount, recipient } => mint(deps, env, info, amount, recipient),
+        ExecuteMsg::MintBatch {
+            recipients,
+            max_total_supply,
+        } => mint_batch(deps, env, info, recipients, max_total_supply),
+        ExecuteMsg::AddMinter { address, allowance } => add_minter(deps, info, address, allowance),
+        ExecuteMsg::RemoveMinter { address } => remove_minter(deps, info, address),
+        ExecuteMsg::Burn { amount } => burn(deps, env, info, amount),
+        ExecuteMsg::Freeze { account, amount } => freeze(deps, env, info, account, amount),
+        ExecuteMsg::Unfreeze { account, amount } => unfreeze(deps, env, info, account, amount),
+        ExecuteMsg::SetFrozen { account, amount } => set_frozen(deps, env, info, account, amount),
+        ExecuteMsg::GloballyFreeze { duration } => globally_freeze(deps, env, info, duration),
+        ExecuteMsg::GloballyUnfreeze {} => globally_unfreeze(deps, env, info),
+        ExecuteMsg::ClearExpiredFreeze {} => clear_expired_freeze(deps, env),
+        ExecuteMsg::SetFeeRecipients { recipients } => set_fee_recipients(deps, info, recipients),
+        ExecuteMsg::DistributeFees {} => distribute_fees(deps, env),
+        ExecuteMsg::SetPoolConfig {
+            base_denom,
+            fee_rate,
+        } => set_pool_config(deps, info, base_denom, fee_rate),
+        ExecuteMsg::ProvideLiquidity {} => provide_liquidity(deps, info),
+        ExecuteMsg::Swap { offer, min_return } => swap(deps, info, offer, min_return),
+        ExecuteMsg::WithdrawLiquidity { shares } => withdraw_liquidity(deps, info, shares),
         ExecuteMsg::SetWhitelistedLimit { account, amount } => {
-            set_whitelisted_limit(deps, info, account, amount)
+            set_whitelisted_limit(deps, env, info, account, amount)
         }
     }
 }
 
 // ********** Transactions **********
 
+fn record_tx(
+    storage: &mut dyn Storage,
+    env: &Env,
+    method: &str,
+    account: String,
+    amount: u128,
+    sender: String,
+) -> StdResult<()> {
+    let id = TX_COUNT.may_load(storage)?.unwrap_or_default();
+    TX_HISTORY.save(
+        storage,
+        id,
+        &TxRecord {
+            method: method.to_string(),
+            account,
+            amount,
+            sender,
+            block_height: env.block.height,
+            block_time: env.block.time.seconds(),
+        },
+    )?;
+    TX_COUNT.save(storage, &(id + 1))?;
+    Ok(())
+}
+
+fn assert_owner_or_minter(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    amount: u128,
+) -> Result<(), ContractError> {
+    if assert_owner(storage, sender).is_ok() {
+        return Ok(());
+    }
+
+    let allowance = MINTERS
+        .may_load(storage, sender)?
+        .ok_or(ContractError::Unauthorized {})?;
+
+    if let Some(remaining) = allowance {
+        let remaining = remaining
+            .checked_sub(amount)
+            .ok_or(ContractError::MinterAllowanceExceeded {})?;
+        MINTERS.save(storage, sender, &Some(remaining))?;
+    }
+
+    Ok(())
+}
+
+fn add_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    allowance: Option<u128>,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let minter = deps.api.addr_validate(&address)?;
+    MINTERS.save(deps.storage, &minter, &allowance)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_minter")
+        .add_attribute("minter", address))
+}
+
+fn remove_minter(deps: DepsMut, info: MessageInfo, address: String) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let minter = deps.api.addr_validate(&address)?;
+    MINTERS.remove(deps.storage, &minter);
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_minter")
+        .add_attribute("minter", address))
+}
+
 fn mint(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     amount: u128,
     recipient: Option<String>,
 ) -> CoreumResult<ContractError> {
-    assert_owner(deps.storage, &info.sender)?;
+    assert_owner_or_minter(deps.storage, &info.sender, amount)?;
     let denom = DENOM.load(deps.storage)?;
     let msg = CoreumMsg::AssetFT(assetft::Msg::Mint {
         coin: coin(amount, denom.clone()),
-        recipient,
+        recipient: recipient.clone(),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "mint",
+        recipient.unwrap_or_else(|| info.sender.to_string()),
+        amount,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "mint")
         .add_attribute("denom", denom)
@@ -97,7 +210,71 @@ fn mint(
         .add_message(msg))
 }
 
-fn burn(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<ContractError> {
+fn mint_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipients: Vec<(String, u128)>,
+    max_total_supply: Option<u128>,
+) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+
+    let mut total_amount: u128 = 0;
+    for (_, amount) in recipients.iter() {
+        total_amount = total_amount
+            .checked_add(*amount)
+            .ok_or(ContractError::SupplyCapExceeded {})?;
+    }
+
+    assert_owner_or_minter(deps.storage, &info.sender, total_amount)?;
+
+    if let Some(max_total_supply) = max_total_supply {
+        let current_supply: SupplyResponse =
+            deps.querier.query(&QueryRequest::Bank(BankQuery::Supply {
+                denom: denom.clone(),
+            }))?;
+
+        let new_total_supply = current_supply
+            .amount
+            .amount
+            .u128()
+            .checked_add(total_amount)
+            .ok_or(ContractError::SupplyCapExceeded {})?;
+
+        if new_total_supply > max_total_supply {
+            return Err(ContractError::SupplyCapExceeded {});
+        }
+    }
+
+    for (recipient, amount) in recipients.iter() {
+        record_tx(
+            deps.storage,
+            &env,
+            "mint_batch",
+            recipient.clone(),
+            *amount,
+            info.sender.to_string(),
+        )?;
+    }
+
+    let messages = recipients
+        .into_iter()
+        .map(|(recipient, amount)| {
+            CoreumMsg::AssetFT(assetft::Msg::Mint {
+                coin: coin(amount, denom.clone()),
+                recipient: Some(recipient),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Response::new()
+        .add_attribute("method", "mint_batch")
+        .add_attribute("denom", denom)
+        .add_attribute("amount", total_amount.to_string())
+        .add_messages(messages))
+}
+
+fn burn(deps: DepsMut, env: Env, info: MessageInfo, amount: u128) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
@@ -105,6 +282,15 @@ fn burn(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<Contract
         coin: coin(amount, denom.clone()),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "burn",
+        env.contract.address.to_string(),
+        amount,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "burn")
         .add_attribute("denom", denom)
@@ -114,6 +300,7 @@ fn burn(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<Contract
 
 fn freeze(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -122,10 +309,19 @@ fn freeze(
     let denom = DENOM.load(deps.storage)?;
 
     let msg = CoreumMsg::AssetFT(assetft::Msg::Freeze {
-        account,
+        account: account.clone(),
         coin: coin(amount, denom.clone()),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "freeze",
+        account,
+        amount,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "freeze")
         .add_attribute("denom", denom)
@@ -135,6 +331,7 @@ fn freeze(
 
 fn unfreeze(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -143,10 +340,19 @@ fn unfreeze(
     let denom = DENOM.load(deps.storage)?;
 
     let msg = CoreumMsg::AssetFT(assetft::Msg::Unfreeze {
-        account,
+        account: account.clone(),
         coin: coin(amount, denom.clone()),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "unfreeze",
+        account,
+        amount,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "unfreeze")
         .add_attribute("denom", denom)
@@ -156,6 +362,7 @@ fn unfreeze(
 
 fn set_frozen(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -164,10 +371,19 @@ fn set_frozen(
     let denom = DENOM.load(deps.storage)?;
 
     let msg = CoreumMsg::AssetFT(assetft::Msg::SetFrozen {
-        account,
+        account: account.clone(),
         coin: coin(amount, denom.clone()),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "set_frozen",
+        account,
+        amount,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "set_frozen")
         .add_attribute("denom", denom)
@@ -175,28 +391,388 @@ fn set_frozen(
         .add_message(msg))
 }
 
-fn globally_freeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractError> {
+fn globally_freeze(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: Option<Duration>,
+) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
+    let expiration = duration.map(|duration| duration.after(&env.block));
+    FREEZE_EXPIRATION.save(deps.storage, &expiration)?;
+
     let msg = CoreumMsg::AssetFT(assetft::Msg::GloballyFreeze {
         denom: denom.clone(),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "globally_freeze",
+        denom.clone(),
+        0,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "globally_freeze")
         .add_attribute("denom", denom)
         .add_message(msg))
 }
 
-fn globally_unfreeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractError> {
+fn clear_expired_freeze(deps: DepsMut, env: Env) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let expiration = FREEZE_EXPIRATION
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::FreezeNotExpired {})?;
+
+    if !expiration.is_expired(&env.block) {
+        return Err(ContractError::FreezeNotExpired {});
+    }
+
+    FREEZE_EXPIRATION.save(deps.storage, &None)?;
+
+    let msg = CoreumMsg::AssetFT(assetft::Msg::GloballyUnfreeze {
+        denom: denom.clone(),
+    });
+
+    record_tx(
+        deps.storage,
+        &env,
+        "clear_expired_freeze",
+        denom.clone(),
+        0,
+        "permissionless".to_string(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "clear_expired_freeze")
+        .add_attribute("denom", denom)
+        .add_message(msg))
+}
+
+fn set_fee_recipients(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipients: Vec<(String, Decimal)>,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    if recipients.iter().any(|(_, weight)| weight.is_zero()) {
+        return Err(ContractError::InvalidFeeWeight {});
+    }
+
+    let recipients = recipients
+        .into_iter()
+        .map(|(address, weight)| Ok((deps.api.addr_validate(&address)?, weight)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    FEE_RECIPIENTS.save(deps.storage, &recipients)?;
+
+    Ok(Response::new().add_attribute("method", "set_fee_recipients"))
+}
+
+fn distribute_fees(deps: DepsMut, env: Env) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let recipients = FEE_RECIPIENTS.load(deps.storage)?;
+    if recipients.is_empty() {
+        return Err(ContractError::NoFeeRecipients {});
+    }
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, denom.clone())?;
+    if balance.amount.is_zero() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    let total_weight = recipients
+        .iter()
+        .try_fold(Decimal::zero(), |acc, (_, weight)| acc.checked_add(*weight))?;
+
+    let mut messages = Vec::with_capacity(recipients.len());
+    let mut distributed = Uint128::zero();
+    for (idx, (recipient, weight)) in recipients.iter().enumerate() {
+        let share = if idx + 1 == recipients.len() {
+            balance.amount - distributed
+        } else {
+            balance
+                .amount
+                .checked_multiply_ratio(weight.atomics(), total_weight.atomics())
+                .map_err(|_| ContractError::InvalidFeeWeight {})?
+        };
+        distributed += share;
+
+        if share.is_zero() {
+            continue;
+        }
+
+        messages.push(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![coin(share.u128(), denom.clone())],
+        });
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "distribute_fees")
+        .add_attribute("denom", denom)
+        .add_attribute("amount", distributed.to_string())
+        .add_messages(messages))
+}
+
+fn amount_of(funds: &[Coin], denom: &str) -> u128 {
+    funds
+        .iter()
+        .find(|coin| coin.denom == denom)
+        .map(|coin| coin.amount.u128())
+        .unwrap_or_default()
+}
+
+fn integer_sqrt(value: u128) -> u128 {
+    if value == 0 {
+        return 0;
+    }
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+fn set_pool_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_denom: String,
+    fee_rate: Decimal,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    if fee_rate >= Decimal::one() {
+        return Err(ContractError::InvalidFeeRate {});
+    }
+
+    let denom = DENOM.load(deps.storage)?;
+    if base_denom == denom {
+        return Err(ContractError::InvalidBaseDenom {});
+    }
+
+    let reserves = POOL_RESERVES.may_load(deps.storage)?;
+    if reserves
+        .as_ref()
+        .is_some_and(|r| r.token_reserve > 0 || r.base_reserve > 0)
+    {
+        return Err(ContractError::PoolAlreadyFunded {});
+    }
+
+    let config = PoolConfig {
+        base_denom,
+        fee_rate,
+    };
+    POOL_CONFIG.save(deps.storage, &config)?;
+    if reserves.is_none() {
+        POOL_RESERVES.save(
+            deps.storage,
+            &PoolReserves {
+                token_reserve: 0,
+                base_reserve: 0,
+            },
+        )?;
+    }
+
+    Ok(Response::new().add_attribute("method", "set_pool_config"))
+}
+
+fn provide_liquidity(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let config = POOL_CONFIG.load(deps.storage)?;
+
+    let token_amount = amount_of(&info.funds, &denom);
+    let base_amount = amount_of(&info.funds, &config.base_denom);
+    if token_amount == 0 || base_amount == 0 {
+        return Err(ContractError::InvalidLiquidityDeposit {});
+    }
+
+    let mut reserves = POOL_RESERVES.load(deps.storage)?;
+    let total_shares = TOTAL_LP_SHARES.may_load(deps.storage)?.unwrap_or_default();
+
+    let minted_shares = if total_shares == 0 {
+        integer_sqrt(
+            token_amount
+                .checked_mul(base_amount)
+                .ok_or(ContractError::InvalidLiquidityDeposit {})?,
+        )
+    } else {
+        std::cmp::min(
+            token_amount.saturating_mul(total_shares) / reserves.token_reserve.max(1),
+            base_amount.saturating_mul(total_shares) / reserves.base_reserve.max(1),
+        )
+    };
+    if minted_shares == 0 {
+        return Err(ContractError::InvalidLiquidityDeposit {});
+    }
+
+    reserves.token_reserve += token_amount;
+    reserves.base_reserve += base_amount;
+    POOL_RESERVES.save(deps.storage, &reserves)?;
+    TOTAL_LP_SHARES.save(deps.storage, &(total_shares + minted_shares))?;
+
+    let owned = LP_SHARES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    LP_SHARES.save(deps.storage, &info.sender, &(owned + minted_shares))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "provide_liquidity")
+        .add_attribute("shares_minted", minted_shares.to_string()))
+}
+
+fn swap(
+    deps: DepsMut,
+    info: MessageInfo,
+    offer: Coin,
+    min_return: Uint128,
+) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let config = POOL_CONFIG.load(deps.storage)?;
+    let mut reserves = POOL_RESERVES.load(deps.storage)?;
+    if reserves.token_reserve == 0 || reserves.base_reserve == 0 {
+        return Err(ContractError::EmptyPool {});
+    }
+
+    let sent = amount_of(&info.funds, &offer.denom);
+    if sent == 0 || sent != offer.amount.u128() {
+        return Err(ContractError::InvalidSwapOffer {});
+    }
+
+    let (offer_reserve, return_reserve, return_denom, offer_is_token) = if offer.denom == denom {
+        (
+            reserves.token_reserve,
+            reserves.base_reserve,
+            config.base_denom.clone(),
+            true,
+        )
+    } else if offer.denom == config.base_denom {
+        (
+            reserves.base_reserve,
+            reserves.token_reserve,
+            denom.clone(),
+            false,
+        )
+    } else {
+        return Err(ContractError::InvalidSwapOffer {});
+    };
+
+    let fee_multiplier = Decimal::one() - config.fee_rate;
+    let offer_after_fee = offer
+        .amount
+        .multiply_ratio(fee_multiplier.atomics(), Decimal::one().atomics())
+        .u128();
+
+    let k = offer_reserve
+        .checked_mul(return_reserve)
+        .ok_or(ContractError::InvalidSwapOffer {})?;
+    let priced_offer_reserve = offer_reserve
+        .checked_add(offer_after_fee)
+        .ok_or(ContractError::InvalidSwapOffer {})?;
+    let new_return_reserve = k / priced_offer_reserve;
+    let return_amount = return_reserve.saturating_sub(new_return_reserve);
+
+    if return_amount < min_return.u128() {
+        return Err(ContractError::SlippageExceeded {});
+    }
+
+    let new_offer_reserve = offer_reserve
+        .checked_add(sent)
+        .ok_or(ContractError::InvalidSwapOffer {})?;
+
+    if offer_is_token {
+        reserves.token_reserve = new_offer_reserve;
+        reserves.base_reserve = new_return_reserve;
+    } else {
+        reserves.base_reserve = new_offer_reserve;
+        reserves.token_reserve = new_return_reserve;
+    }
+    POOL_RESERVES.save(deps.storage, &reserves)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "swap")
+        .add_attribute("return_amount", return_amount.to_string())
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(return_amount, return_denom)],
+        }))
+}
+
+fn withdraw_liquidity(
+    deps: DepsMut,
+    info: MessageInfo,
+    shares: u128,
+) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let config = POOL_CONFIG.load(deps.storage)?;
+    let mut reserves = POOL_RESERVES.load(deps.storage)?;
+    let total_shares = TOTAL_LP_SHARES.load(deps.storage)?;
+
+    let owned = LP_SHARES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if shares == 0 || shares > owned {
+        return Err(ContractError::InsufficientShares {});
+    }
+
+    let token_amount = Uint128::new(reserves.token_reserve)
+        .multiply_ratio(shares, total_shares)
+        .u128();
+    let base_amount = Uint128::new(reserves.base_reserve)
+        .multiply_ratio(shares, total_shares)
+        .u128();
+
+    reserves.token_reserve -= token_amount;
+    reserves.base_reserve -= base_amount;
+    POOL_RESERVES.save(deps.storage, &reserves)?;
+    TOTAL_LP_SHARES.save(deps.storage, &(total_shares - shares))?;
+
+    let remaining = owned - shares;
+    if remaining == 0 {
+        LP_SHARES.remove(deps.storage, &info.sender);
+    } else {
+        LP_SHARES.save(deps.storage, &info.sender, &remaining)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw_liquidity")
+        .add_attribute("shares_burned", shares.to_string())
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![
+                coin(token_amount, denom),
+                coin(base_amount, config.base_denom),
+            ],
+        }))
+}
+
+fn globally_unfreeze(deps: DepsMut, env: Env, info: MessageInfo) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
+    FREEZE_EXPIRATION.save(deps.storage, &None)?;
 
     let msg = CoreumMsg::AssetFT(assetft::Msg::GloballyUnfreeze {
         denom: denom.clone(),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "globally_unfreeze",
+        denom.clone(),
+        0,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "globally_unfreeze")
         .add_attribute("denom", denom)
@@ -205,6 +781,7 @@ fn globally_unfreeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractE
 
 fn set_whitelisted_limit(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -213,10 +790,19 @@ fn set_whitelisted_limit(
     let denom = DENOM.load(deps.storage)?;
 
     let msg = CoreumMsg::AssetFT(assetft::Msg::SetWhitelistedLimit {
-        account,
+        account: account.clone(),
         coin: coin(amount, denom.clone()),
     });
 
+    record_tx(
+        deps.storage,
+        &env,
+        "set_whitelisted_limit",
+        account,
+        amount,
+        info.sender.to_string(),
+    )?;
+
     Ok(Response::new()
         .add_attribute("method", "set_whitelisted_limit")
         .add_attribute("denom", denom)
@@ -226,7 +812,7 @@ fn set_whitelisted_limit(
 
 // ********** Queries **********
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps<CoreumQueries>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
         QueryMsg::Token {} => to_json_binary(&query_token(deps)?),
@@ -237,6 +823,11 @@ pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<B
         QueryMsg::WhitelistedBalance { account } => {
             to_json_binary(&query_whitelisted_balance(deps, account)?)
         }
+        QueryMsg::TransactionHistory { start_after, limit } => {
+            to_json_binary(&query_transaction_history(deps, start_after, limit)?)
+        }
+        QueryMsg::FreezeStatus {} => to_json_binary(&query_freeze_status(deps, env)?),
+        QueryMsg::Pool {} => to_json_binary(&query_pool(deps)?),
         QueryMsg::Balance { account } => to_json_binary(&query_balance(deps, account)?),
         QueryMsg::FrozenBalances { account } => {
             to_json_binary(&query_frozen_balances(deps, account)?)
@@ -387,3 +978,349 @@ fn query_whitelisted_balances(
     };
     Ok(res)
 }
+
+fn query_transaction_history(
+    deps: Deps<CoreumQueries>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TransactionHistoryResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .min(MAX_HISTORY_LIMIT) as usize;
+    let count = TX_COUNT.may_load(deps.storage)?.unwrap_or_default();
+    let start = start_after.unwrap_or(count);
+
+    let records = TX_HISTORY
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::exclusive(start)),
+            Order::Descending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TransactionHistoryResponse { records })
+}
+
+fn query_freeze_status(deps: Deps<CoreumQueries>, env: Env) -> StdResult<FreezeStatusResponse> {
+    let token = query_token(deps)?.token;
+    let expiration = FREEZE_EXPIRATION.may_load(deps.storage)?.flatten();
+    let lapsed = expiration
+        .map(|expiration| expiration.is_expired(&env.block))
+        .unwrap_or(false);
+
+    Ok(FreezeStatusResponse {
+        frozen: token.globally_frozen,
+        lapsed,
+        expires_at: expiration,
+    })
+}
+
+fn query_pool(deps: Deps<CoreumQueries>) -> StdResult<PoolResponse> {
+    let config = POOL_CONFIG.load(deps.storage)?;
+    let reserves = POOL_RESERVES.load(deps.storage)?;
+    let total_shares = TOTAL_LP_SHARES.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(PoolResponse {
+        base_denom: config.base_denom,
+        fee_rate: config.fee_rate,
+        token_reserve: reserves.token_reserve,
+        base_reserve: reserves.base_reserve,
+        total_shares,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn assert_owner_or_minter_rejects_exhausted_allowance() {
+        let mut deps = mock_dependencies();
+        let minter = Addr::unchecked("minter");
+        MINTERS
+            .save(deps.as_mut().storage, &minter, &Some(100))
+            .unwrap();
+
+        let err = assert_owner_or_minter(deps.as_mut().storage, &minter, 150).unwrap_err();
+        assert_eq!(err, ContractError::MinterAllowanceExceeded {});
+
+        assert_owner_or_minter(deps.as_mut().storage, &minter, 40).unwrap();
+        let remaining = MINTERS.load(deps.as_ref().storage, &minter).unwrap();
+        assert_eq!(remaining, Some(60));
+    }
+
+    #[test]
+    fn mint_batch_rejects_total_amount_overflow() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+        DENOM
+            .save(deps.as_mut().storage, &"denom".to_string())
+            .unwrap();
+
+        let err = mint_batch(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            vec![
+                ("recipient1".to_string(), u128::MAX),
+                ("recipient2".to_string(), 1),
+            ],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::SupplyCapExceeded {});
+    }
+
+    #[test]
+    fn mint_batch_rejects_max_total_supply_exceeded() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+        DENOM
+            .save(deps.as_mut().storage, &"denom".to_string())
+            .unwrap();
+        // Seeds the mock bank querier's tracked supply of "denom" to 500.
+        deps.querier
+            .update_balance("existing_holder", vec![coin(500, "denom")]);
+
+        let err = mint_batch(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            vec![("recipient1".to_string(), 200)],
+            Some(600),
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::SupplyCapExceeded {});
+    }
+
+    #[test]
+    fn set_fee_recipients_rejects_zero_weight() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+
+        let err = set_fee_recipients(
+            deps.as_mut(),
+            mock_info("owner", &[]),
+            vec![
+                ("recipient1".to_string(), Decimal::percent(50)),
+                ("recipient2".to_string(), Decimal::zero()),
+            ],
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::InvalidFeeWeight {});
+    }
+
+    #[test]
+    fn distribute_fees_rejects_zero_balance() {
+        let mut deps = mock_dependencies();
+        DENOM
+            .save(deps.as_mut().storage, &"denom".to_string())
+            .unwrap();
+        FEE_RECIPIENTS
+            .save(
+                deps.as_mut().storage,
+                &vec![(Addr::unchecked("recipient1"), Decimal::one())],
+            )
+            .unwrap();
+
+        let err = distribute_fees(deps.as_mut(), mock_env()).unwrap_err();
+
+        assert_eq!(err, ContractError::NothingToDistribute {});
+    }
+
+    #[test]
+    fn distribute_fees_splits_pro_rata() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        DENOM
+            .save(deps.as_mut().storage, &"denom".to_string())
+            .unwrap();
+        FEE_RECIPIENTS
+            .save(
+                deps.as_mut().storage,
+                &vec![
+                    (Addr::unchecked("recipient1"), Decimal::percent(75)),
+                    (Addr::unchecked("recipient2"), Decimal::percent(25)),
+                ],
+            )
+            .unwrap();
+        deps.querier
+            .update_balance(env.contract.address.clone(), vec![coin(100, "denom")]);
+
+        let res = distribute_fees(deps.as_mut(), env).unwrap();
+
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(
+            res.attributes
+                .iter()
+                .find(|attr| attr.key == "amount")
+                .unwrap()
+                .value,
+            "100"
+        );
+    }
+
+    fn setup_pool(deps: DepsMut) {
+        DENOM.save(deps.storage, &"token".to_string()).unwrap();
+        POOL_CONFIG
+            .save(
+                deps.storage,
+                &PoolConfig {
+                    base_denom: "base".to_string(),
+                    fee_rate: Decimal::percent(10),
+                },
+            )
+            .unwrap();
+        POOL_RESERVES
+            .save(
+                deps.storage,
+                &PoolReserves {
+                    token_reserve: 1000,
+                    base_reserve: 1000,
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn swap_credits_full_offer_amount_to_reserves() {
+        let mut deps = mock_dependencies();
+        setup_pool(deps.as_mut());
+
+        let res = swap(
+            deps.as_mut(),
+            mock_info("trader", &[coin(100, "token")]),
+            coin(100, "token"),
+            Uint128::zero(),
+        )
+        .unwrap();
+
+        let reserves = POOL_RESERVES.load(deps.as_ref().storage).unwrap();
+        // The full 100 sent must be credited, not just the fee-discounted 90
+        // used for pricing, or the fee portion would be stranded forever.
+        assert_eq!(reserves.token_reserve, 1100);
+        assert_eq!(reserves.base_reserve, 1000 - 83);
+        assert_eq!(
+            res.attributes
+                .iter()
+                .find(|attr| attr.key == "return_amount")
+                .unwrap()
+                .value,
+            "83"
+        );
+    }
+
+    #[test]
+    fn swap_rejects_when_return_below_min() {
+        let mut deps = mock_dependencies();
+        setup_pool(deps.as_mut());
+
+        let err = swap(
+            deps.as_mut(),
+            mock_info("trader", &[coin(100, "token")]),
+            coin(100, "token"),
+            Uint128::new(1000),
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::SlippageExceeded {});
+    }
+
+    #[test]
+    fn set_pool_config_rejects_self_paired_denom() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+        DENOM
+            .save(deps.as_mut().storage, &"token".to_string())
+            .unwrap();
+
+        let err = set_pool_config(
+            deps.as_mut(),
+            mock_info("owner", &[]),
+            "token".to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::InvalidBaseDenom {});
+    }
+
+    #[test]
+    fn set_pool_config_rejects_reconfiguration_once_funded() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+        setup_pool(deps.as_mut());
+
+        let err = set_pool_config(
+            deps.as_mut(),
+            mock_info("owner", &[]),
+            "other_base".to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::PoolAlreadyFunded {});
+    }
+
+    #[test]
+    fn clear_expired_freeze_rejects_before_expiration() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+        DENOM
+            .save(deps.as_mut().storage, &"denom".to_string())
+            .unwrap();
+        let env = mock_env();
+
+        globally_freeze(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            Some(Duration::Time(1000)),
+        )
+        .unwrap();
+
+        let err = clear_expired_freeze(deps.as_mut(), env).unwrap_err();
+
+        assert_eq!(err, ContractError::FreezeNotExpired {});
+    }
+
+    #[test]
+    fn clear_expired_freeze_clears_once_expiration_lapses() {
+        let mut deps = mock_dependencies();
+        cw_ownable::initialize_owner(deps.as_mut().storage, deps.as_mut().api, Some("owner"))
+            .unwrap();
+        DENOM
+            .save(deps.as_mut().storage, &"denom".to_string())
+            .unwrap();
+        let env = mock_env();
+
+        globally_freeze(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            Some(Duration::Time(1000)),
+        )
+        .unwrap();
+
+        let mut later_env = env;
+        later_env.block.time = later_env.block.time.plus_seconds(1001);
+
+        clear_expired_freeze(deps.as_mut(), later_env).unwrap();
+
+        let expiration = FREEZE_EXPIRATION.load(deps.as_ref().storage).unwrap();
+        assert_eq!(expiration, None);
+    }
+}
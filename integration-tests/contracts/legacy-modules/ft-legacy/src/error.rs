@@ -0,0 +1,63 @@
+use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError};
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    MultiplyRatio(#[from] CheckedMultiplyRatioError),
+
+    #[error("minting the requested amount would exceed the maximum total supply")]
+    SupplyCapExceeded {},
+
+    #[error("sender is neither the owner nor a registered minter")]
+    Unauthorized {},
+
+    #[error("mint amount exceeds the sender's remaining minter allowance")]
+    MinterAllowanceExceeded {},
+
+    #[error("global freeze has no expiration, or has not yet lapsed")]
+    FreezeNotExpired {},
+
+    #[error("no fee recipients are configured")]
+    NoFeeRecipients {},
+
+    #[error("there is no collected commission balance to distribute")]
+    NothingToDistribute {},
+
+    #[error("fee recipient weights must be greater than zero")]
+    InvalidFeeWeight {},
+
+    #[error("pool fee rate must be less than 1")]
+    InvalidFeeRate {},
+
+    #[error("pool base denom cannot be the same as the token denom")]
+    InvalidBaseDenom {},
+
+    #[error("pool cannot be reconfigured once it holds liquidity")]
+    PoolAlreadyFunded {},
+
+    #[error("liquidity must be provided in both the token denom and the pool's base denom")]
+    InvalidLiquidityDeposit {},
+
+    #[error("the pool has no reserves to swap against")]
+    EmptyPool {},
+
+    #[error("swap offer coin does not match the funds sent or is not part of the pool")]
+    InvalidSwapOffer {},
+
+    #[error("swap return is below the requested minimum return")]
+    SlippageExceeded {},
+
+    #[error("sender does not own enough LP shares to withdraw")]
+    InsufficientShares {},
+}
@@ -0,0 +1,130 @@
+use coreum_wasm_sdk::assetft::Feature;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use cw_utils::{Duration, Expiration};
+
+use crate::state::TxRecord;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub symbol: String,
+    pub subunit: String,
+    pub precision: u32,
+    pub initial_amount: Uint128,
+    pub description: Option<String>,
+    pub features: Option<Vec<Feature>>,
+    pub burn_rate: Decimal,
+    pub send_commission_rate: Decimal,
+    pub uri: Option<String>,
+    pub uri_hash: Option<String>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Mint {
+        amount: u128,
+        recipient: Option<String>,
+    },
+    MintBatch {
+        recipients: Vec<(String, u128)>,
+        max_total_supply: Option<u128>,
+    },
+    AddMinter {
+        address: String,
+        allowance: Option<u128>,
+    },
+    RemoveMinter {
+        address: String,
+    },
+    Burn {
+        amount: u128,
+    },
+    Freeze {
+        account: String,
+        amount: u128,
+    },
+    Unfreeze {
+        account: String,
+        amount: u128,
+    },
+    SetFrozen {
+        account: String,
+        amount: u128,
+    },
+    GloballyFreeze {
+        duration: Option<Duration>,
+    },
+    GloballyUnfreeze {},
+    ClearExpiredFreeze {},
+    SetFeeRecipients {
+        recipients: Vec<(String, Decimal)>,
+    },
+    DistributeFees {},
+    SetPoolConfig {
+        base_denom: String,
+        fee_rate: Decimal,
+    },
+    ProvideLiquidity {},
+    Swap {
+        offer: Coin,
+        min_return: Uint128,
+    },
+    WithdrawLiquidity {
+        shares: u128,
+    },
+    SetWhitelistedLimit {
+        account: String,
+        amount: u128,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(coreum_wasm_sdk::assetft::ParamsResponse)]
+    Params {},
+    #[returns(coreum_wasm_sdk::assetft::TokenResponse)]
+    Token {},
+    #[returns(coreum_wasm_sdk::assetft::TokensResponse)]
+    Tokens { issuer: String },
+    #[returns(coreum_wasm_sdk::assetft::BalanceResponse)]
+    Balance { account: String },
+    #[returns(coreum_wasm_sdk::assetft::FrozenBalanceResponse)]
+    FrozenBalance { account: String },
+    #[returns(coreum_wasm_sdk::assetft::FrozenBalancesResponse)]
+    FrozenBalances { account: String },
+    #[returns(coreum_wasm_sdk::assetft::WhitelistedBalanceResponse)]
+    WhitelistedBalance { account: String },
+    #[returns(coreum_wasm_sdk::assetft::WhitelistedBalancesResponse)]
+    WhitelistedBalances { account: String },
+    #[returns(TransactionHistoryResponse)]
+    TransactionHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(FreezeStatusResponse)]
+    FreezeStatus {},
+    #[returns(PoolResponse)]
+    Pool {},
+}
+
+#[cw_serde]
+pub struct PoolResponse {
+    pub base_denom: String,
+    pub fee_rate: Decimal,
+    pub token_reserve: u128,
+    pub base_reserve: u128,
+    pub total_shares: u128,
+}
+
+#[cw_serde]
+pub struct TransactionHistoryResponse {
+    pub records: Vec<TxRecord>,
+}
+
+#[cw_serde]
+pub struct FreezeStatusResponse {
+    pub frozen: bool,
+    pub lapsed: bool,
+    pub expires_at: Option<Expiration>,
+}
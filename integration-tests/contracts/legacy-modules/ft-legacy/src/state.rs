@@ -0,0 +1,43 @@
+use cosmwasm_std::{Addr, Decimal};
+use cw_utils::Expiration;
+
+use cosmwasm_schema::cw_serde;
+use cw_storage_plus::{Item, Map};
+
+pub const DENOM: Item<String> = Item::new("denom");
+
+pub const MINTERS: Map<&Addr, Option<u128>> = Map::new("minters");
+
+pub const FREEZE_EXPIRATION: Item<Option<Expiration>> = Item::new("freeze_expiration");
+
+pub const FEE_RECIPIENTS: Item<Vec<(Addr, Decimal)>> = Item::new("fee_recipients");
+
+pub const POOL_CONFIG: Item<PoolConfig> = Item::new("pool_config");
+pub const POOL_RESERVES: Item<PoolReserves> = Item::new("pool_reserves");
+pub const LP_SHARES: Map<&Addr, u128> = Map::new("lp_shares");
+pub const TOTAL_LP_SHARES: Item<u128> = Item::new("total_lp_shares");
+
+#[cw_serde]
+pub struct PoolConfig {
+    pub base_denom: String,
+    pub fee_rate: Decimal,
+}
+
+#[cw_serde]
+pub struct PoolReserves {
+    pub token_reserve: u128,
+    pub base_reserve: u128,
+}
+
+pub const TX_COUNT: Item<u64> = Item::new("tx_count");
+pub const TX_HISTORY: Map<u64, TxRecord> = Map::new("tx_history");
+
+#[cw_serde]
+pub struct TxRecord {
+    pub method: String,
+    pub account: String,
+    pub amount: u128,
+    pub sender: String,
+    pub block_height: u64,
+    pub block_time: u64,
+}